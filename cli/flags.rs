@@ -0,0 +1,350 @@
+// Copyright 2018-2019 the Deno authors. All rights reserved. MIT license.
+use clap::App;
+use clap::Arg;
+use clap::ArgMatches;
+use clap::SubCommand;
+
+// Creates vector of strings, Vec<String>
+macro_rules! svec {
+  ($($x:expr),*) => (vec![$($x.to_string()),*]);
+}
+
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct DenoFlags {
+  pub log_debug: bool,
+  pub log_json: bool,
+  pub version: bool,
+  pub reload: bool,
+  pub watch: bool,
+  pub config_path: Option<String>,
+  pub allow_read: bool,
+  pub allow_write: bool,
+  pub allow_net: bool,
+  pub allow_env: bool,
+  pub allow_run: bool,
+  pub allow_high_precision: bool,
+  pub no_prompts: bool,
+  pub v8_flags: Option<Vec<String>>,
+  pub xeval_replvar: Option<String>,
+  pub xeval_delim: Option<String>,
+  // Emit `deno info` output as a JSON document instead of decorated text.
+  pub info_json: bool,
+  // Custom REPL history file; `None` uses the default under `deno_dir`.
+  pub repl_history_path: Option<String>,
+  // Disable REPL history persistence entirely.
+  pub no_repl_history: bool,
+  // Path of the single-file artifact emitted by `deno bundle`.
+  pub bundle_output: Option<String>,
+}
+
+static ENV_VARIABLES_HELP: &str = "ENVIRONMENT VARIABLES:
+    DENO_DIR       Set deno's base directory
+    NO_COLOR       Set to disable color";
+
+fn create_cli_app<'a, 'b>() -> App<'a, 'b> {
+  App::new("deno")
+    .setting(clap::AppSettings::AllowExternalSubcommands)
+    .after_help(ENV_VARIABLES_HELP)
+    .arg(
+      Arg::with_name("log-debug")
+        .short("D")
+        .long("log-debug")
+        .help("Log debug output")
+        .global(true),
+    ).arg(
+      Arg::with_name("log-json")
+        .long("log-json")
+        .help("Emit logs as JSON objects to stderr")
+        .global(true),
+    ).arg(
+      Arg::with_name("reload")
+        .short("r")
+        .long("reload")
+        .help("Reload source code cache (recompile TypeScript)")
+        .global(true),
+    ).arg(
+      Arg::with_name("config")
+        .short("c")
+        .long("config")
+        .value_name("FILE")
+        .help("Load compiler configuration file")
+        .takes_value(true)
+        .global(true),
+    ).arg(
+      Arg::with_name("v8-options")
+        .long("v8-options")
+        .help("Print V8 command line options")
+        .global(true),
+    ).arg(
+      Arg::with_name("v8-flags")
+        .long("v8-flags")
+        .takes_value(true)
+        .use_delimiter(true)
+        .require_equals(true)
+        .help("Set V8 command line options")
+        .global(true),
+    ).arg(
+      Arg::with_name("allow-read")
+        .long("allow-read")
+        .help("Allow file system read access")
+        .global(true),
+    ).arg(
+      Arg::with_name("allow-write")
+        .long("allow-write")
+        .help("Allow file system write access")
+        .global(true),
+    ).arg(
+      Arg::with_name("allow-net")
+        .long("allow-net")
+        .help("Allow network access")
+        .global(true),
+    ).arg(
+      Arg::with_name("allow-env")
+        .long("allow-env")
+        .help("Allow environment access")
+        .global(true),
+    ).arg(
+      Arg::with_name("allow-run")
+        .long("allow-run")
+        .help("Allow running subprocesses")
+        .global(true),
+    ).arg(
+      Arg::with_name("allow-high-precision")
+        .long("allow-high-precision")
+        .help("Allow high precision time measurement")
+        .global(true),
+    ).arg(
+      Arg::with_name("allow-all")
+        .short("A")
+        .long("allow-all")
+        .help("Allow all permissions")
+        .global(true),
+    ).arg(
+      Arg::with_name("no-prompt")
+        .long("no-prompt")
+        .help("Do not use prompts")
+        .global(true),
+    ).arg(
+      Arg::with_name("repl-history")
+        .long("repl-history")
+        .value_name("FILE")
+        .takes_value(true)
+        .help("Set the REPL history file path")
+        .global(true),
+    ).arg(
+      Arg::with_name("no-repl-history")
+        .long("no-repl-history")
+        .help("Disable persistent REPL history")
+        .global(true),
+    ).subcommand(
+      SubCommand::with_name("version")
+        .about("Print the version")
+        .setting(clap::AppSettings::DisableVersion),
+    ).subcommand(
+      SubCommand::with_name("bundle")
+        .about("Emit a single self-contained JS file for a module")
+        .arg(Arg::with_name("source_file").takes_value(true).required(true))
+        .arg(Arg::with_name("out_file").takes_value(true).required(true)),
+    ).subcommand(
+      SubCommand::with_name("fetch")
+        .about("Fetch the dependencies")
+        .arg(Arg::with_name("file").takes_value(true).required(true)),
+    ).subcommand(
+      SubCommand::with_name("types").about("Print runtime TypeScript declarations"),
+    ).subcommand(
+      SubCommand::with_name("info")
+        .about("Show info about cache or info related to source file")
+        .arg(
+          Arg::with_name("json")
+            .long("json")
+            .help("Output module metadata as JSON"),
+        ).arg(Arg::with_name("file").takes_value(true).required(true)),
+    ).subcommand(
+      SubCommand::with_name("eval")
+        .about("Eval script")
+        .arg(Arg::with_name("code").takes_value(true).required(true)),
+    ).subcommand(
+      SubCommand::with_name("xeval")
+        .about("Eval a script on lines of stdin")
+        .arg(
+          Arg::with_name("replvar")
+            .long("replvar")
+            .takes_value(true)
+            .help("Set variable name to be used in eval, defaults to $"),
+        ).arg(
+          Arg::with_name("delim")
+            .short("d")
+            .long("delim")
+            .takes_value(true)
+            .help("Set delimiter, defaults to newline"),
+        ).arg(Arg::with_name("code").takes_value(true).required(true)),
+    ).subcommand(
+      SubCommand::with_name("test")
+        .about("Run tests")
+        .arg(
+          Arg::with_name("files")
+            .help("List of file names or globs to run")
+            .multiple(true),
+        ),
+    ).subcommand(
+      SubCommand::with_name("run")
+        .setting(clap::AppSettings::TrailingVarArg)
+        .about("Run a program given a filename or url to the source code")
+        .arg(
+          Arg::with_name("watch")
+            .long("watch")
+            .help("Watch the dependency graph and re-run on changes"),
+        ).arg(Arg::with_name("script_arg").multiple(true)),
+    )
+}
+
+/// Parse ArgMatches into internal DenoFlags structure.
+/// This method should not make any side effects.
+pub fn parse_flags(matches: ArgMatches) -> DenoFlags {
+  let mut flags = DenoFlags::default();
+
+  if matches.is_present("log-debug") {
+    flags.log_debug = true;
+  }
+  if matches.is_present("log-json") {
+    flags.log_json = true;
+  }
+  if matches.is_present("version") {
+    flags.version = true;
+  }
+  if matches.is_present("reload") {
+    flags.reload = true;
+  }
+  flags.config_path = matches.value_of("config").map(ToOwned::to_owned);
+  if matches.is_present("allow-read") {
+    flags.allow_read = true;
+  }
+  if matches.is_present("allow-write") {
+    flags.allow_write = true;
+  }
+  if matches.is_present("allow-net") {
+    flags.allow_net = true;
+  }
+  if matches.is_present("allow-env") {
+    flags.allow_env = true;
+  }
+  if matches.is_present("allow-run") {
+    flags.allow_run = true;
+  }
+  if matches.is_present("allow-high-precision") {
+    flags.allow_high_precision = true;
+  }
+  if matches.is_present("allow-all") {
+    flags.allow_read = true;
+    flags.allow_write = true;
+    flags.allow_net = true;
+    flags.allow_env = true;
+    flags.allow_run = true;
+    flags.allow_high_precision = true;
+  }
+  if matches.is_present("no-prompt") {
+    flags.no_prompts = true;
+  }
+  flags.repl_history_path =
+    matches.value_of("repl-history").map(ToOwned::to_owned);
+  if matches.is_present("no-repl-history") {
+    flags.no_repl_history = true;
+  }
+  if let Some(v8_flags) = matches.values_of("v8-flags") {
+    let mut v8_flags_vec: Vec<String> =
+      v8_flags.map(String::from).collect();
+    v8_flags_vec.insert(0, "deno".to_string());
+    flags.v8_flags = Some(v8_flags_vec);
+  }
+
+  flags
+}
+
+/// Used for `deno fetch`/`deno info`/etc. to pull the single file argument
+/// out of a subcommand's matches.
+fn single_arg(matches: &ArgMatches, name: &str) -> Vec<String> {
+  match matches.value_of(name) {
+    Some(value) => svec!["deno", value],
+    None => svec!["deno"],
+  }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DenoSubcommand {
+  Bundle,
+  Eval,
+  Fetch,
+  Info,
+  Repl,
+  Run,
+  Test,
+  Types,
+  Version,
+  Xeval,
+}
+
+pub fn flags_from_vec(
+  args: Vec<String>,
+) -> (DenoFlags, DenoSubcommand, Vec<String>) {
+  let cli_app = create_cli_app();
+  let matches = cli_app.get_matches_from(args);
+
+  let mut flags = parse_flags(matches.clone());
+
+  let subcommand = match matches.subcommand() {
+    ("bundle", Some(bundle_match)) => {
+      flags.bundle_output =
+        bundle_match.value_of("out_file").map(ToOwned::to_owned);
+      DenoSubcommand::Bundle
+    }
+    ("eval", Some(_eval_match)) => DenoSubcommand::Eval,
+    ("fetch", Some(_fetch_match)) => DenoSubcommand::Fetch,
+    ("info", Some(info_match)) => {
+      if info_match.is_present("json") {
+        flags.info_json = true;
+      }
+      DenoSubcommand::Info
+    }
+    ("types", Some(_)) => DenoSubcommand::Types,
+    ("version", Some(_)) => DenoSubcommand::Version,
+    ("xeval", Some(xeval_match)) => {
+      flags.xeval_replvar =
+        Some(xeval_match.value_of("replvar").unwrap_or("$").to_owned());
+      flags.xeval_delim = xeval_match.value_of("delim").map(ToOwned::to_owned);
+      DenoSubcommand::Xeval
+    }
+    ("run", Some(run_match)) => {
+      if run_match.is_present("watch") {
+        flags.watch = true;
+      }
+      DenoSubcommand::Run
+    }
+    ("test", Some(_test_match)) => DenoSubcommand::Test,
+    _ => DenoSubcommand::Repl,
+  };
+
+  let argv = match matches.subcommand() {
+    ("bundle", Some(m)) => single_arg(m, "source_file"),
+    ("eval", Some(m)) => single_arg(m, "code"),
+    ("fetch", Some(m)) => single_arg(m, "file"),
+    ("info", Some(m)) => single_arg(m, "file"),
+    ("xeval", Some(m)) => single_arg(m, "code"),
+    ("run", Some(m)) => {
+      let mut v = svec!["deno"];
+      if let Some(scripts) = m.values_of("script_arg") {
+        v.extend(scripts.map(String::from));
+      }
+      v
+    }
+    ("test", Some(m)) => {
+      let mut v = svec!["deno"];
+      if let Some(files) = m.values_of("files") {
+        v.extend(files.map(String::from));
+      }
+      v
+    }
+    _ => svec!["deno"],
+  };
+
+  (flags, subcommand, argv)
+}
@@ -12,6 +12,7 @@ extern crate deno;
 #[cfg(unix)]
 extern crate nix;
 extern crate rand;
+extern crate rustyline;
 
 mod ansi;
 pub mod compiler;
@@ -52,11 +53,15 @@ use flags::DenoSubcommand;
 use futures::lazy;
 use futures::Future;
 use log::{LevelFilter, Metadata, Record};
+use rustyline::error::ReadlineError;
 use std::env;
 
-static LOGGER: Logger = Logger;
+static LOGGER_PRETTY: Logger = Logger { json: false };
+static LOGGER_JSON: Logger = Logger { json: true };
 
-struct Logger;
+struct Logger {
+  json: bool,
+}
 
 impl log::Log for Logger {
   fn enabled(&self, metadata: &Metadata) -> bool {
@@ -72,7 +77,23 @@ impl log::Log for Logger {
         target.push_str(&line_no.to_string());
       }
 
-      println!("{} RS - {} - {}", record.level(), target, record.args());
+      if self.json {
+        // One JSON object per record to stderr so logs stay machine-parseable
+        // when embedding the runtime in larger systems.
+        let record = json!({
+          "level": record.level().to_string(),
+          "target": record.target(),
+          "line": record.line(),
+          "msg": record.args().to_string(),
+          "ts": std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0),
+        });
+        eprintln!("{}", record);
+      } else {
+        println!("{} RS - {} - {}", record.level(), target, record.args());
+      }
     }
   }
   fn flush(&self) {}
@@ -94,14 +115,53 @@ where
 
 // TODO(ry) Move this to main.rs
 pub fn print_file_info(worker: &Worker, url: &str) {
+  print_file_info_impl(worker, url, false);
+}
+
+// Emit the same module metadata as `print_file_info` as a single JSON
+// document so editors and tooling can consume it without scraping output.
+pub fn print_file_info_json(worker: &Worker, url: &str) {
+  print_file_info_impl(worker, url, true);
+}
+
+// Recursively serialize the dependency tree exposed by `worker.modules.deps`.
+fn deps_to_json(deps: &deno::Deps) -> serde_json::Value {
+  let children: Vec<serde_json::Value> = deps
+    .deps
+    .as_ref()
+    .map(|ds| ds.iter().map(deps_to_json).collect())
+    .unwrap_or_default();
+  json!({
+    "name": deps.name,
+    "deps": children,
+  })
+}
+
+fn print_file_info_impl(worker: &Worker, url: &str, json_output: bool) {
   let maybe_out =
     worker::fetch_module_meta_data_and_maybe_compile(&worker.state, url, ".");
   if let Err(err) = maybe_out {
-    println!("{}", err);
+    if json_output {
+      println!("{}", json!({ "error": err.to_string() }));
+    } else {
+      println!("{}", err);
+    }
     return;
   }
   let out = maybe_out.unwrap();
 
+  if json_output {
+    let doc = json!({
+      "local": out.filename,
+      "type": msg::enum_name_media_type(out.media_type),
+      "compiled": out.maybe_output_code_filename,
+      "map": out.maybe_source_map_filename,
+      "deps": worker.modules.deps(&out.module_name).map(|d| deps_to_json(&d)),
+    });
+    println!("{}", serde_json::to_string_pretty(&doc).unwrap());
+    return;
+  }
+
   println!("{} {}", ansi::bold("local:".to_string()), &(out.filename));
 
   println!(
@@ -141,6 +201,19 @@ pub fn print_file_info(worker: &Worker, url: &str) {
   }
 }
 
+// Flatten the recursive dependency graph exposed by `worker.modules.deps`
+// into a list of module names in load order, visiting each module once.
+fn collect_module_deps(deps: &deno::Deps, seen: &mut Vec<String>) {
+  if let Some(ref children) = deps.deps {
+    for child in children {
+      collect_module_deps(child, seen);
+    }
+  }
+  if !seen.contains(&deps.name) {
+    seen.push(deps.name.clone());
+  }
+}
+
 fn create_worker_and_state(
   flags: DenoFlags,
   argv: Vec<String>,
@@ -181,6 +254,7 @@ fn fetch_or_info_command(
   argv: Vec<String>,
   print_info: bool,
 ) {
+  let info_json = flags.info_json;
   let (mut worker, state) = create_worker_and_state(flags, argv);
 
   let main_module = state.main_module().unwrap();
@@ -195,7 +269,11 @@ fn fetch_or_info_command(
       .execute_mod_async(&main_url, true)
       .and_then(move |worker| {
         if print_info {
-          print_file_info(&worker, &main_module);
+          if info_json {
+            print_file_info_json(&worker, &main_module);
+          } else {
+            print_file_info(&worker, &main_module);
+          }
         }
         worker.then(|result| {
           js_check(result);
@@ -206,6 +284,120 @@ fn fetch_or_info_command(
   tokio_util::run(main_future);
 }
 
+// Minimal synchronous AMD loader prepended to every bundle so the emitted
+// `define(name, factory)` blocks register into a local module table and the
+// trailing `require(mainModule)` can resolve the graph without any external
+// loader at runtime.
+static AMD_LOADER_SHIM: &str = r#"(function () {
+  var modules = {};
+  var cache = {};
+  function require(name) {
+    if (cache[name]) {
+      return cache[name].exports;
+    }
+    var factory = modules[name];
+    if (!factory) {
+      throw new Error("Module not found: " + name);
+    }
+    var module = { exports: {} };
+    cache[name] = module;
+    factory(require, module.exports, module);
+    return module.exports;
+  }
+  this.define = function (name, factory) {
+    modules[name] = factory;
+  };
+  this.require = require;
+})();
+"#;
+
+fn bundle_command(flags: DenoFlags, argv: Vec<String>) {
+  let out_file = flags.bundle_output.clone().unwrap();
+  let (mut worker, state) = create_worker_and_state(flags, argv);
+
+  let main_module = state.main_module().unwrap();
+  let main_future = lazy(move || {
+    // Setup runtime.
+    js_check(worker.execute("denoMain()"));
+    debug!("main_module {}", main_module);
+
+    let main_url = root_specifier_to_url(&main_module).unwrap();
+
+    worker
+      .execute_mod_async(&main_url, true)
+      .and_then(move |worker| {
+        // Resolve the raw specifier to its canonical module URL before keying
+        // the dependency graph on it, exactly as `print_file_info` does.
+        let main_out = worker::fetch_module_meta_data_and_maybe_compile(
+          &worker.state,
+          &main_module,
+          ".",
+        ).unwrap_or_else(|err| {
+          print_err_and_exit(RustOrJsError::from(err));
+          unreachable!()
+        });
+        let deps = worker
+          .modules
+          .deps(&main_out.module_name)
+          .unwrap_or_else(|| {
+            print_err_and_exit(RustOrJsError::from(crate::errors::new(
+              crate::msg::ErrorKind::Other,
+              "cannot retrieve full dependency graph".to_string(),
+            )));
+            unreachable!()
+          });
+
+        let mut module_names = Vec::new();
+        collect_module_deps(&deps, &mut module_names);
+
+        // Wrap every resolved module into a single AMD-style output file so
+        // the cached graph can be shipped without re-fetching at runtime.
+        // A tiny synchronous module registry is prepended so the emitted
+        // `define`/`require` calls resolve against the bundle itself rather
+        // than an undefined global loader.
+        let mut bundle = String::from(AMD_LOADER_SHIM);
+        for name in &module_names {
+          let out = worker::fetch_module_meta_data_and_maybe_compile(
+            &worker.state,
+            name,
+            ".",
+          ).unwrap_or_else(|err| {
+            print_err_and_exit(RustOrJsError::from(err));
+            unreachable!()
+          });
+          // Wrap the *compiled* JavaScript (imports/exports already lowered to
+          // require/exports), not the raw TS/ESM source, so the bundle is
+          // runnable. Plain JS modules have no separate compiled file.
+          let source = match out.maybe_output_code_filename {
+            Some(ref compiled) => {
+              std::fs::read_to_string(compiled).unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                std::process::exit(1);
+              })
+            }
+            None => String::from_utf8_lossy(&out.source_code).into_owned(),
+          };
+          bundle.push_str(&format!(
+            "define({:?}, function (require, exports, module) {{\n{}\n}});\n",
+            name, source
+          ));
+        }
+        bundle.push_str(&format!("require({:?});\n", main_out.module_name));
+
+        if let Err(err) = std::fs::write(&out_file, bundle) {
+          eprintln!("{}", err);
+          std::process::exit(1);
+        }
+
+        worker.then(|result| {
+          js_check(result);
+          Ok(())
+        })
+      }).map_err(|(err, _worker)| print_err_and_exit(err))
+  });
+  tokio_util::run(main_future);
+}
+
 fn eval_command(flags: DenoFlags, argv: Vec<String>) {
   let (mut worker, state) = create_worker_and_state(flags, argv);
   // Wrap provided script in async function so asynchronous methods
@@ -232,6 +424,231 @@ fn eval_command(flags: DenoFlags, argv: Vec<String>) {
   tokio_util::run(main_future);
 }
 
+// Expand a single path/glob argument into concrete test files. A plain file
+// is taken as-is; a directory is walked for files matching the test naming
+// convention; anything containing glob metacharacters is matched against the
+// filesystem. Returns the matches in sorted order.
+fn discover_test_files(arg: &str) -> Vec<std::path::PathBuf> {
+  use std::path::{Path, PathBuf};
+
+  fn is_test_file(path: &Path) -> bool {
+    match path.file_name().and_then(|n| n.to_str()) {
+      Some(name) => {
+        (name.ends_with("_test.ts")
+          || name.ends_with("_test.js")
+          || name.ends_with(".test.ts")
+          || name.ends_with(".test.js"))
+      }
+      None => false,
+    }
+  }
+
+  // Shell-style wildcard match. `?` and `*` match within a single path
+  // segment, while `**` spans separators so directory-component wildcards
+  // (e.g. `src/**/*_test.ts`) match nested files instead of nothing.
+  fn glob_match(pattern: &str, text: &str) -> bool {
+    let (p, t): (Vec<char>, Vec<char>) =
+      (pattern.chars().collect(), text.chars().collect());
+    fn rec(p: &[char], t: &[char]) -> bool {
+      match p.first() {
+        None => t.is_empty(),
+        Some('*') if p.get(1) == Some(&'*') => {
+          rec(&p[2..], t) || (!t.is_empty() && rec(p, &t[1..]))
+        }
+        Some('*') => {
+          rec(&p[1..], t)
+            || (!t.is_empty() && t[0] != '/' && rec(p, &t[1..]))
+        }
+        Some('?') => !t.is_empty() && t[0] != '/' && rec(&p[1..], &t[1..]),
+        Some(&c) => !t.is_empty() && c == t[0] && rec(&p[1..], &t[1..]),
+      }
+    }
+    rec(&p, &t)
+  }
+
+  // Longest leading run of path components with no wildcard — the directory
+  // to start walking from when expanding a glob.
+  fn glob_base(pattern: &str) -> PathBuf {
+    let mut base = PathBuf::new();
+    for comp in Path::new(pattern).components() {
+      let part = comp.as_os_str().to_string_lossy();
+      if part.contains('*') || part.contains('?') {
+        break;
+      }
+      base.push(comp);
+    }
+    if base.as_os_str().is_empty() {
+      base.push(".");
+    }
+    base
+  }
+
+  fn walk(dir: &Path, out: &mut Vec<PathBuf>) {
+    if let Ok(entries) = std::fs::read_dir(dir) {
+      for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        if path.is_dir() {
+          walk(&path, out);
+        } else if is_test_file(&path) {
+          out.push(path);
+        }
+      }
+    }
+  }
+
+  // Unfiltered variant used for glob expansion, where the pattern itself
+  // decides what matches rather than the test-file naming convention.
+  fn walk_all(dir: &Path, out: &mut Vec<PathBuf>) {
+    if let Ok(entries) = std::fs::read_dir(dir) {
+      for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        if path.is_dir() {
+          walk_all(&path, out);
+        } else {
+          out.push(path);
+        }
+      }
+    }
+  }
+
+  let mut matches = Vec::new();
+  let path = Path::new(arg);
+  if path.is_file() {
+    matches.push(path.to_path_buf());
+  } else if path.is_dir() {
+    walk(path, &mut matches);
+  } else if arg.contains('*') || arg.contains('?') {
+    let mut all = Vec::new();
+    walk_all(&glob_base(arg), &mut all);
+    // Patterns with a separator match against the whole relative path so
+    // directory wildcards apply; bare filename patterns match the file name.
+    let multi_segment = arg.contains('/');
+    for candidate in all {
+      let hay = if multi_segment {
+        candidate.to_string_lossy().into_owned()
+      } else {
+        match candidate.file_name().and_then(|n| n.to_str()) {
+          Some(name) => name.to_string(),
+          None => continue,
+        }
+      };
+      if glob_match(arg, &hay) {
+        matches.push(candidate);
+      }
+    }
+  }
+  matches.sort();
+  matches
+}
+
+fn test_command(flags: DenoFlags, argv: Vec<String>) {
+  // Discover the concrete test files behind the glob/path arguments, then
+  // resolve each to a module URL.
+  let mut test_modules = Vec::new();
+  for arg in &argv[1..] {
+    for file in discover_test_files(arg) {
+      let url = root_specifier_to_url(&file.to_string_lossy()).unwrap();
+      let url = url.to_string();
+      if !test_modules.contains(&url) {
+        test_modules.push(url);
+      }
+    }
+  }
+
+  if test_modules.is_empty() {
+    eprintln!("No test files found");
+    std::process::exit(1);
+  }
+
+  // Synthesize an entry module that imports every discovered test file and
+  // invokes the registered test runner, mirroring how `eval_command` wraps a
+  // generated top level source. Written to a temp file so it can run through
+  // the existing `execute_mod_async` path.
+  let imports = test_modules
+    .iter()
+    .map(|url| format!("import {:?};", url))
+    .collect::<Vec<_>>()
+    .join("\n");
+  // Per-process temp paths keep concurrent `deno test` runs from racing on a
+  // shared file. They are removed after the summary is reported; a run that
+  // aborts with an error exits first and leaves them in place.
+  let entry_path = std::env::temp_dir()
+    .join(format!("deno_test_entry_{}.ts", std::process::id()));
+  let stats_path = std::env::temp_dir()
+    .join(format!("deno_test_stats_{}.json", std::process::id()));
+
+  // The test runner lives in the std `testing` module, not the builtin
+  // `deno` namespace. Rather than letting the isolate call `Deno.exit` (which
+  // would terminate the process before the temp files can be cleaned up), the
+  // entry writes `runTests`' `stats` out so Rust can report the summary and
+  // choose the exit code itself.
+  let test_source = format!(
+    "{}\nimport {{ runTests }} from \"https://deno.land/std/testing/mod.ts\";\n(async () => {{
+        const {{ stats }} = await runTests();
+        const data = new TextEncoder().encode(JSON.stringify(stats));
+        Deno.writeFileSync({stats:?}, data);
+      }})();\n",
+    imports,
+    stats = stats_path.to_string_lossy(),
+  );
+
+  if let Err(err) = std::fs::write(&entry_path, test_source) {
+    eprintln!("{}", err);
+    std::process::exit(1);
+  }
+  let entry_url =
+    root_specifier_to_url(&entry_path.to_string_lossy()).unwrap();
+
+  let (mut worker, _state) = create_worker_and_state(flags, argv);
+  let main_future = lazy(move || {
+    js_check(worker.execute("denoMain()"));
+    worker
+      .execute_mod_async(&entry_url, false)
+      .and_then(|worker| {
+        worker.then(|result| {
+          js_check(result);
+          Ok(())
+        })
+      }).map_err(|(err, _worker)| print_err_and_exit(err))
+  });
+  tokio_util::run(main_future);
+
+  // Report the pass/fail summary captured from the runner, then clean up.
+  let exit_code = report_test_summary(&stats_path);
+  let _ = std::fs::remove_file(&entry_path);
+  let _ = std::fs::remove_file(&stats_path);
+  std::process::exit(exit_code);
+}
+
+// Read the `stats` JSON the entry module wrote, print a pass/fail summary
+// through the `ansi` helpers, and return the process exit code (non-zero when
+// any test failed or the stats could not be read).
+fn report_test_summary(stats_path: &std::path::Path) -> i32 {
+  let stats = std::fs::read_to_string(stats_path)
+    .ok()
+    .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok());
+  let stats = match stats {
+    Some(stats) => stats,
+    None => {
+      eprintln!("{}", ansi::bold("test runner produced no results".to_string()));
+      return 1;
+    }
+  };
+  let passed = stats["passed"].as_u64().unwrap_or(0);
+  let failed = stats["failed"].as_u64().unwrap_or(0);
+  println!(
+    "{} {} passed; {} failed",
+    ansi::bold("test result:".to_string()),
+    passed,
+    failed
+  );
+  if failed > 0 {
+    1
+  } else {
+    0
+  }
+}
+
 fn xeval_command(flags: DenoFlags, argv: Vec<String>) {
   let xeval_replvar = flags.xeval_replvar.clone().unwrap();
   let (mut worker, state) = create_worker_and_state(flags, argv);
@@ -257,13 +674,45 @@ fn xeval_command(flags: DenoFlags, argv: Vec<String>) {
   tokio_util::run(main_future);
 }
 
+// Resolve the REPL history file from the flags: `--no-repl-history` disables
+// persistence, `--repl-history` overrides the location, and the default lives
+// next to the compiled-code cache under `deno_dir`.
+fn resolve_repl_history(state: &ThreadSafeState) -> Option<std::path::PathBuf> {
+  if state.flags.no_repl_history {
+    None
+  } else if let Some(ref path) = state.flags.repl_history_path {
+    Some(std::path::PathBuf::from(path))
+  } else {
+    Some(state.dir.root.join("deno_history.txt"))
+  }
+}
+
 fn run_repl(flags: DenoFlags, argv: Vec<String>) {
-  let (mut worker, _state) = create_worker_and_state(flags, argv);
+  let (mut worker, state) = create_worker_and_state(flags, argv);
+
+  // A persistent, searchable line editor (up-arrow recall and Ctrl-R reverse
+  // search come from rustyline) seeded from the history file resolved off the
+  // flags. `None` means `--no-repl-history` — run without touching disk.
+  let mut repl = repl::Repl::new(resolve_repl_history(&state));
 
   // REPL situation.
   let main_future = lazy(move || {
     // Setup runtime.
     js_check(worker.execute("denoMain()"));
+
+    // Read-eval loop: each line is evaluated in the running isolate and
+    // appended to history. Ctrl-C/Ctrl-D (Interrupted/Eof) end the session.
+    loop {
+      match repl.readline("> ") {
+        Ok(line) => js_check(worker.execute(&line)),
+        Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof) => break,
+        Err(err) => {
+          eprintln!("{}", err);
+          break;
+        }
+      }
+    }
+
     worker
       .then(|result| {
         js_check(result);
@@ -276,6 +725,10 @@ fn run_repl(flags: DenoFlags, argv: Vec<String>) {
 }
 
 fn run_script(flags: DenoFlags, argv: Vec<String>) {
+  if flags.watch {
+    return watch_script(flags, argv);
+  }
+
   let (mut worker, state) = create_worker_and_state(flags, argv);
 
   let main_module = state.main_module().unwrap();
@@ -299,14 +752,135 @@ fn run_script(flags: DenoFlags, argv: Vec<String>) {
   tokio_util::run(main_future);
 }
 
+// Re-executes the main module whenever any file in its resolved dependency
+// graph changes. Unlike `run_script`, a run that ends in a JS error does not
+// abort the process — we report it and keep watching. Implemented by polling
+// mtimes so no extra crate dependency is pulled in.
+fn watch_script(mut flags: DenoFlags, argv: Vec<String>) {
+  use std::collections::HashMap;
+  use std::path::PathBuf;
+  use std::sync::{Arc, Mutex};
+  use std::time::{Duration, SystemTime};
+
+  let mtimes = |paths: &[PathBuf]| -> HashMap<PathBuf, Option<SystemTime>> {
+    paths
+      .iter()
+      .map(|p| {
+        let mtime =
+          std::fs::metadata(p).and_then(|m| m.modified()).ok();
+        (p.clone(), mtime)
+      }).collect()
+  };
+
+  loop {
+    // A fresh Worker on every iteration so re-runs see re-compiled modules.
+    let (mut worker, state) =
+      create_worker_and_state(flags.clone(), argv.clone());
+    let main_module = state.main_module().unwrap();
+
+    // Filled in from inside the future once the graph has resolved.
+    let watched: Arc<Mutex<Vec<PathBuf>>> = Arc::new(Mutex::new(Vec::new()));
+    let watched_inner = watched.clone();
+
+    let main_future = lazy(move || {
+      js_check(worker.execute("denoMain()"));
+      debug!("main_module {}", main_module);
+
+      let main_url = root_specifier_to_url(&main_module).unwrap();
+
+      worker.execute_mod_async(&main_url, false).then(move |result| {
+        let worker = match result {
+          Ok(worker) => worker,
+          Err((err, worker)) => {
+            // A failed run still registers whatever graph resolved so the
+            // watcher can pick up the fix.
+            eprintln!("{}", err.to_string());
+            worker
+          }
+        };
+        *watched_inner.lock().unwrap() =
+          collect_watch_targets(&worker, &main_module);
+        worker.then(|result| {
+          if let Err(err) = result {
+            eprintln!("{}", RustOrJsError::from(err).to_string());
+          }
+          Ok(())
+        })
+      })
+    });
+    tokio_util::run(main_future);
+
+    let watched = Arc::try_unwrap(watched).unwrap().into_inner().unwrap();
+    if watched.is_empty() {
+      // Nothing resolved (e.g. the main module itself failed to load) — there
+      // is nothing to watch, so exit rather than block forever.
+      eprintln!("No files to watch");
+      return;
+    }
+
+    eprintln!("{}", ansi::bold("watching for changes...".to_string()));
+    let baseline = mtimes(&watched);
+    loop {
+      std::thread::sleep(Duration::from_millis(200));
+      if mtimes(&watched) != baseline {
+        // Debounce a burst of writes by letting them settle before re-running.
+        std::thread::sleep(Duration::from_millis(200));
+        break;
+      }
+    }
+
+    // Force the rebuilt Worker to reload and recompile every module in the
+    // graph, so stale compiled-code cache entries of changed modules are not
+    // reused.
+    flags.reload = true;
+  }
+}
+
+// Local filesystem paths backing every module in the resolved graph.
+fn collect_watch_targets(
+  worker: &Worker,
+  main_module: &str,
+) -> Vec<std::path::PathBuf> {
+  let mut paths = Vec::new();
+  // Resolve the raw specifier to its canonical module URL before keying the
+  // dependency graph on it, as `bundle_command` does.
+  let main_name = match worker::fetch_module_meta_data_and_maybe_compile(
+    &worker.state,
+    main_module,
+    ".",
+  ) {
+    Ok(out) => out.module_name,
+    Err(_) => return paths,
+  };
+  if let Some(deps) = worker.modules.deps(&main_name) {
+    let mut names = Vec::new();
+    collect_module_deps(&deps, &mut names);
+    for name in names {
+      if let Ok(out) = worker::fetch_module_meta_data_and_maybe_compile(
+        &worker.state,
+        &name,
+        ".",
+      ) {
+        paths.push(std::path::PathBuf::from(out.filename));
+      }
+    }
+  }
+  paths
+}
+
 fn main() {
   #[cfg(windows)]
   ansi_term::enable_ansi_support().ok(); // For Windows 10
 
-  log::set_logger(&LOGGER).unwrap();
   let args: Vec<String> = env::args().collect();
   let (flags, subcommand, argv) = flags::flags_from_vec(args);
 
+  log::set_logger(if flags.log_json {
+    &LOGGER_JSON
+  } else {
+    &LOGGER_PRETTY
+  }).unwrap();
+
   if let Some(ref v8_flags) = flags.v8_flags {
     v8_set_flags(v8_flags.clone());
   }
@@ -318,11 +892,13 @@ fn main() {
   });
 
   match subcommand {
+    DenoSubcommand::Bundle => bundle_command(flags, argv),
     DenoSubcommand::Eval => eval_command(flags, argv),
     DenoSubcommand::Fetch => fetch_or_info_command(flags, argv, false),
     DenoSubcommand::Info => fetch_or_info_command(flags, argv, true),
     DenoSubcommand::Repl => run_repl(flags, argv),
     DenoSubcommand::Run => run_script(flags, argv),
+    DenoSubcommand::Test => test_command(flags, argv),
     DenoSubcommand::Types => types_command(),
     DenoSubcommand::Version => run_repl(flags, argv),
     DenoSubcommand::Xeval => xeval_command(flags, argv),
@@ -0,0 +1,68 @@
+// Copyright 2018-2019 the Deno authors. All rights reserved. MIT license.
+use std::path::PathBuf;
+
+use rustyline::error::ReadlineError;
+use rustyline::Editor;
+
+/// Interactive REPL line editor backed by rustyline.
+///
+/// Evaluated lines are appended to `history_file` (resolved from `deno_dir` or
+/// the `--repl-history` flag) and reloaded on startup, so context is retained
+/// across invocations. rustyline provides up-arrow recall and reverse
+/// incremental search (Ctrl-R) over that history for free. Persistence is
+/// skipped entirely when `history_file` is `None` (`--no-repl-history`).
+pub struct Repl {
+  editor: Editor<()>,
+  history_file: Option<PathBuf>,
+}
+
+impl Repl {
+  pub fn new(history_file: Option<PathBuf>) -> Repl {
+    let mut repl = Repl {
+      editor: Editor::<()>::new(),
+      history_file,
+    };
+
+    repl.load_history();
+    repl
+  }
+
+  fn load_history(&mut self) {
+    if let Some(ref history_file) = self.history_file {
+      debug!("Loading REPL history: {:?}", history_file);
+      self
+        .editor
+        .load_history(history_file)
+        .map_err(|e| {
+          debug!("Unable to load history file: {:?} {}", history_file, e)
+        })
+        // ignore this error (e.g. it occurs on the first run)
+        .ok();
+    }
+  }
+
+  fn save_history(&mut self) {
+    if let Some(ref history_file) = self.history_file {
+      self
+        .editor
+        .save_history(history_file)
+        .map(|_| debug!("Saved REPL history to: {:?}", history_file))
+        .map_err(|e| eprintln!("Unable to save REPL history: {:?} {}", history_file, e))
+        .ok();
+    }
+  }
+
+  pub fn readline(&mut self, prompt: &str) -> Result<String, ReadlineError> {
+    self.editor.readline(&prompt).map(|line| {
+      self.editor.add_history_entry(line.clone());
+      self.save_history();
+      line
+    })
+  }
+}
+
+impl Drop for Repl {
+  fn drop(&mut self) {
+    self.save_history();
+  }
+}